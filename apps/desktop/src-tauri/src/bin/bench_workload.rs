@@ -0,0 +1,181 @@
+//! Workload runner for tracking anonymization throughput and catching
+//! detection-speed regressions as presets, layers, or `minimum_confidence`
+//! change.
+//!
+//! Usage: `bench_workload <workload.json> [results.json]`
+//!
+//! The workload file is a named list of entries, each specifying `text` or
+//! `input_path`, a `preset`, and an `iterations` count. Every entry is run
+//! through the Python sidecar `iterations` times; wall-clock latency
+//! (min/median/p95), `findings_count`, and the per-entity `summary` from the
+//! last run are recorded. Results are written as machine-readable JSON
+//! (workload name, git commit, per-entry timings) so CI or a local script
+//! can diff runs.
+//!
+//! All entries share one [`Sidecar`], so only the first timed call of the
+//! first entry includes the daemon's interpreter/model-load cold start; every
+//! later call measures detection throughput alone. `input_path` entries
+//! are validated against [`ScopeConfig`], same as the Tauri commands.
+//!
+//! Run this from `apps/desktop/src-tauri` (its cwd), same as `cargo tauri
+//! dev` — `scope.config.json` and the dev-mode sidecar script path are both
+//! resolved relative to it.
+
+use legal_anonymizer::python_sidecar::{PythonResponse, Sidecar};
+use legal_anonymizer::scope::ScopeConfig;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::process::Command;
+use std::time::Instant;
+
+#[derive(Debug, Deserialize)]
+struct Workload {
+    name: String,
+    entries: Vec<WorkloadEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkloadEntry {
+    name: String,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    input_path: Option<String>,
+    preset: Value,
+    #[serde(default = "default_iterations")]
+    iterations: u32,
+}
+
+fn default_iterations() -> u32 {
+    1
+}
+
+#[derive(Debug, Serialize)]
+struct LatencyStats {
+    min_ms: f64,
+    median_ms: f64,
+    p95_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct EntryResult {
+    name: String,
+    latency: LatencyStats,
+    findings_count: u32,
+    summary: HashMap<String, u32>,
+}
+
+#[derive(Debug, Serialize)]
+struct WorkloadResults {
+    workload: String,
+    git_commit: String,
+    entries: Vec<EntryResult>,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let Some(workload_path) = args.get(1) else {
+        eprintln!("usage: bench_workload <workload.json> [results.json]");
+        std::process::exit(2);
+    };
+
+    let workload: Workload = serde_json::from_str(
+        &fs::read_to_string(workload_path).expect("failed to read workload file"),
+    )
+    .expect("failed to parse workload json");
+
+    let scope = ScopeConfig::load_standalone().expect("failed to load scope.config.json");
+    let sidecar = Sidecar::new();
+
+    let mut entries = Vec::with_capacity(workload.entries.len());
+    for entry in &workload.entries {
+        entries.push(run_entry(&sidecar, &scope, entry));
+    }
+
+    let results = WorkloadResults {
+        workload: workload.name,
+        git_commit: git_commit(),
+        entries,
+    };
+
+    let json = serde_json::to_string_pretty(&results).expect("failed to serialize results");
+    match args.get(2) {
+        Some(path) => fs::write(path, json).expect("failed to write results file"),
+        None => println!("{json}"),
+    }
+}
+
+fn run_entry(sidecar: &Sidecar, scope: &ScopeConfig, entry: &WorkloadEntry) -> EntryResult {
+    let command = if entry.input_path.is_some() {
+        "analyze_file"
+    } else {
+        "analyze_text"
+    };
+
+    if let Some(input_path) = &entry.input_path {
+        scope
+            .validate_input_path(input_path)
+            .unwrap_or_else(|e| panic!("workload entry {:?}: {e}", entry.name));
+    }
+
+    let mut payload = serde_json::json!({ "preset": entry.preset });
+    if let Some(input_path) = &entry.input_path {
+        payload["input_path"] = Value::String(input_path.clone());
+    }
+    if let Some(text) = &entry.text {
+        payload["text"] = Value::String(text.clone());
+    }
+
+    let mut latencies_ms = Vec::with_capacity(entry.iterations.max(1) as usize);
+    let mut last_response = Value::Null;
+    for _ in 0..entry.iterations.max(1) {
+        let start = Instant::now();
+        let PythonResponse { data } = sidecar
+            .call(scope, command, payload.clone())
+            .expect("sidecar call failed");
+        last_response = data;
+        latencies_ms.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    latencies_ms.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let findings_count = last_response
+        .get("findings_count")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32;
+    let summary = last_response
+        .get("summary")
+        .and_then(|s| serde_json::from_value(s.clone()).ok())
+        .unwrap_or_default();
+
+    EntryResult {
+        name: entry.name.clone(),
+        latency: LatencyStats {
+            min_ms: percentile(&latencies_ms, 0.0),
+            median_ms: percentile(&latencies_ms, 0.5),
+            p95_ms: percentile(&latencies_ms, 0.95),
+        },
+        findings_count,
+        summary,
+    }
+}
+
+fn percentile(sorted_ms: &[f64], p: f64) -> f64 {
+    if sorted_ms.is_empty() {
+        return 0.0;
+    }
+    let idx = (((sorted_ms.len() - 1) as f64) * p).round() as usize;
+    sorted_ms[idx]
+}
+
+fn git_commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
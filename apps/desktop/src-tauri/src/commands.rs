@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use tauri::{AppHandle, Emitter, State};
 
-use crate::python_sidecar::{run_python_command, PythonResponse};
+use crate::python_sidecar::{PythonResponse, RunRegistry, Sidecar};
+use crate::scope::ScopeConfig;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Preset {
@@ -40,10 +42,16 @@ pub struct AnalyzeTextResponse {
 
 #[tauri::command]
 pub fn analyze_text(
+    sidecar: State<Sidecar>,
+    scope: State<ScopeConfig>,
     text: String,
     preset: Preset,
     model_path: Option<String>,
 ) -> Result<AnalyzeTextResponse, String> {
+    if let Some(path) = &model_path {
+        scope.validate_model_path(path).map_err(|e| e.to_string())?;
+    }
+
     let req = AnalyzeTextRequest {
         text,
         preset,
@@ -51,9 +59,10 @@ pub fn analyze_text(
     };
 
     let payload = serde_json::to_value(&req).map_err(|e| e.to_string())?;
-    let res: PythonResponse = run_python_command("analyze_text", payload).map_err(|e| e.to_string())?;
+    let res: PythonResponse = sidecar
+        .call(&scope, "analyze_text", payload)
+        .map_err(|e| e.to_string())?;
 
-    // Parse response
     let response: AnalyzeTextResponse = serde_json::from_value(res.data)
         .map_err(|e| format!("Failed to parse response: {}", e))?;
 
@@ -79,19 +88,156 @@ pub struct AnalyzeFileResponse {
     pub findings_count: u32,
 }
 
+/// Validates `input_path` before the call and the sidecar-reported
+/// `output_path` after it.
 #[tauri::command]
-pub fn analyze_file(input_path: String, preset: Preset) -> Result<AnalyzeFileResponse, String> {
+pub fn analyze_file(
+    sidecar: State<Sidecar>,
+    scope: State<ScopeConfig>,
+    input_path: String,
+    preset: Preset,
+) -> Result<AnalyzeFileResponse, String> {
+    scope
+        .validate_input_path(&input_path)
+        .map_err(|e| e.to_string())?;
+
     let req = AnalyzeFileRequest { input_path, preset };
 
     let payload = serde_json::to_value(&req).map_err(|e| e.to_string())?;
-    let res: PythonResponse = run_python_command("analyze_file", payload).map_err(|e| e.to_string())?;
+    let res: PythonResponse = sidecar
+        .call(&scope, "analyze_file", payload)
+        .map_err(|e| e.to_string())?;
 
     let response: AnalyzeFileResponse = serde_json::from_value(res.data)
         .map_err(|e| format!("Failed to parse response: {}", e))?;
+    scope
+        .validate_output_path(&response.output_path)
+        .map_err(|e| e.to_string())?;
+
+    Ok(response)
+}
+
+// ============================================================================
+// Streaming File Analysis
+// ============================================================================
+
+/// Like [`analyze_file`], but emits `analyze-started` with a `run_id`
+/// (usable by [`cancel_run`]) and `analyze-progress` for each progress line.
+#[tauri::command]
+pub fn analyze_file_streaming(
+    app: AppHandle,
+    sidecar: State<Sidecar>,
+    registry: State<RunRegistry>,
+    scope: State<ScopeConfig>,
+    input_path: String,
+    preset: Preset,
+) -> Result<AnalyzeFileResponse, String> {
+    scope
+        .validate_input_path(&input_path)
+        .map_err(|e| e.to_string())?;
+
+    let req = AnalyzeFileRequest { input_path, preset };
+    let payload = serde_json::to_value(&req).map_err(|e| e.to_string())?;
+
+    let request_id = sidecar.next_run_id();
+    let run_id = format!("run-{request_id}");
+    registry.register(run_id.clone(), request_id);
+    let _ = app.emit("analyze-started", &run_id);
+
+    let result = sidecar.call_with_id(&scope, request_id, "analyze_file", payload, |event| {
+        let _ = app.emit("analyze-progress", event);
+    });
+    registry.unregister(&run_id);
+
+    let response: AnalyzeFileResponse =
+        serde_json::from_value(result.map_err(|e| e.to_string())?.data)
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+    scope
+        .validate_output_path(&response.output_path)
+        .map_err(|e| e.to_string())?;
 
     Ok(response)
 }
 
+// ============================================================================
+// Batch Analysis
+// ============================================================================
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BatchItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnalyzeBatchRequest {
+    items: Vec<BatchItem>,
+    preset: Preset,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BatchItemResult {
+    pub run_id: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub findings_count: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AnalyzeBatchResponse {
+    pub results: Vec<BatchItemResult>,
+    pub summary: HashMap<String, u32>,
+}
+
+/// Analyze a whole folder of documents in one sidecar round trip. Each
+/// [`BatchItem`] carries either an `input_path` or inline `text`; `summary`
+/// aggregates entity counts across every item.
+#[tauri::command]
+pub fn analyze_batch(
+    sidecar: State<Sidecar>,
+    scope: State<ScopeConfig>,
+    inputs: Vec<BatchItem>,
+    preset: Preset,
+) -> Result<AnalyzeBatchResponse, String> {
+    for item in &inputs {
+        if let Some(path) = &item.input_path {
+            scope.validate_input_path(path).map_err(|e| e.to_string())?;
+        }
+    }
+
+    let req = AnalyzeBatchRequest {
+        items: inputs,
+        preset,
+    };
+
+    let payload = serde_json::to_value(&req).map_err(|e| e.to_string())?;
+    let res: PythonResponse = sidecar
+        .call(&scope, "analyze_batch", payload)
+        .map_err(|e| e.to_string())?;
+
+    let response: AnalyzeBatchResponse = serde_json::from_value(res.data)
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    Ok(response)
+}
+
+// ============================================================================
+// Cancellation
+// ============================================================================
+
+/// Abort an in-flight `analyze_file_streaming` run started with the given
+/// `run_id`. Returns `true` if a running analysis was actually cancelled.
+#[tauri::command]
+pub fn cancel_run(run_id: String, sidecar: State<Sidecar>, registry: State<RunRegistry>) -> bool {
+    match registry.request_id_for(&run_id) {
+        Some(request_id) => sidecar.cancel(request_id),
+        None => false,
+    }
+}
+
 // ============================================================================
 // Supported Extensions
 // ============================================================================
@@ -102,9 +248,13 @@ pub struct SupportedExtensionsResponse {
 }
 
 #[tauri::command]
-pub fn get_supported_extensions() -> Result<SupportedExtensionsResponse, String> {
+pub fn get_supported_extensions(
+    sidecar: State<Sidecar>,
+    scope: State<ScopeConfig>,
+) -> Result<SupportedExtensionsResponse, String> {
     let payload = serde_json::json!({});
-    let res: PythonResponse = run_python_command("get_supported_extensions", payload)
+    let res: PythonResponse = sidecar
+        .call(&scope, "get_supported_extensions", payload)
         .map_err(|e| e.to_string())?;
 
     let response: SupportedExtensionsResponse = serde_json::from_value(res.data)
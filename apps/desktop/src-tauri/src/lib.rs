@@ -0,0 +1,6 @@
+//! Shared library behind the `legal-anonymizer` Tauri binary and standalone
+//! tools such as `bin/bench_workload.rs`.
+
+pub mod commands;
+pub mod python_sidecar;
+pub mod scope;
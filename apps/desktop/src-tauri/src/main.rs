@@ -1,17 +1,25 @@
 // Prevent console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod commands;
-mod python_sidecar;
-
-use tauri::Builder;
+use legal_anonymizer::{commands, python_sidecar, scope};
+use tauri::{Builder, Manager};
 
 fn main() {
     Builder::default()
         .plugin(tauri_plugin_dialog::init())
+        .manage(python_sidecar::Sidecar::new())
+        .manage(python_sidecar::RunRegistry::new())
+        .setup(|app| {
+            let scope_config = scope::ScopeConfig::load(app.handle())?;
+            app.manage(scope_config);
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             commands::analyze_text,
             commands::analyze_file,
+            commands::analyze_file_streaming,
+            commands::analyze_batch,
+            commands::cancel_run,
             commands::get_supported_extensions,
         ])
         .run(tauri::generate_context!())
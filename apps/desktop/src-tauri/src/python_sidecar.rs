@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::io::Write;
-use std::process::{Command, Stdio};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use thiserror::Error;
 
+use crate::scope::ScopeConfig;
+
 #[derive(Debug, Error)]
 pub enum SidecarError {
     #[error("failed to start python: {0}")]
@@ -12,6 +17,62 @@ pub enum SidecarError {
     NonZero(String),
     #[error("invalid python output: {0}")]
     InvalidOutput(String),
+    #[error("python sidecar died and was restarted, the request must be retried")]
+    Restarted,
+    #[error("bundled sidecar not found: {0}")]
+    NotBundled(String),
+    #[error("run was cancelled")]
+    Cancelled,
+    #[error("path is outside the allowed scope: {0}")]
+    ScopeDenied(String),
+}
+
+/// Kill a process by pid, since `std::process` can only kill an owned `Child`.
+fn kill_pid(pid: u32) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill").arg("-9").arg(pid.to_string()).status();
+    }
+    #[cfg(windows)]
+    {
+        let _ = Command::new("taskkill")
+            .args(["/F", "/PID", &pid.to_string()])
+            .status();
+    }
+}
+
+/// Build the command used to launch the sidecar for the current build. In
+/// release this resolves the `sidecar_entrypoint` externalBin (see
+/// `tauri.conf.json`) next to the installed exe; in dev it falls back to
+/// `scope.python_interpreter`.
+fn sidecar_command(scope: &ScopeConfig) -> Result<Command, SidecarError> {
+    #[cfg(debug_assertions)]
+    {
+        let mut cmd = Command::new(&scope.python_interpreter);
+        cmd.arg("../../engine/python/scripts/sidecar_entrypoint.py");
+        Ok(cmd)
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        let _ = scope;
+        let exe_dir = std::env::current_exe()
+            .map_err(|e| SidecarError::NotBundled(e.to_string()))?
+            .parent()
+            .ok_or_else(|| {
+                SidecarError::NotBundled("app executable has no parent directory".into())
+            })?
+            .to_path_buf();
+        let binary_name = format!("sidecar_entrypoint{}", std::env::consts::EXE_SUFFIX);
+        let binary_path = exe_dir.join(binary_name);
+        if !binary_path.exists() {
+            return Err(SidecarError::NotBundled(format!(
+                "{} is missing; add \"binaries/sidecar_entrypoint\" to tauri.conf.json's bundle.externalBin",
+                binary_path.display()
+            )));
+        }
+        Ok(Command::new(binary_path))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -20,71 +81,247 @@ pub struct PythonResponse {
     pub data: Value,
 }
 
-/// Run a Python sidecar command with the given payload.
-///
-/// # Arguments
-/// * `command` - The command name (e.g., "analyze_text", "analyze_file")
-/// * `payload` - JSON payload to send to the Python script
-///
-/// # Returns
-/// * `PythonResponse` containing the JSON response from Python
-pub fn run_python_command(command: &str, payload: Value) -> Result<PythonResponse, SidecarError> {
-    // In development, use system Python
-    // In production, this would use Tauri's externalBin
-    let mut cmd = Command::new("python");
-    cmd.arg("../../engine/python/scripts/sidecar_entrypoint.py")
-        .arg(command)
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-
-    let mut child = cmd
-        .spawn()
-        .map_err(|e| SidecarError::StartFailed(e.to_string()))?;
-
-    // Write payload to stdin
-    {
+/// A running `sidecar_entrypoint.py --daemon` process and its open pipes.
+struct DaemonHandle {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+/// Long-lived Python sidecar, kept in Tauri managed state so the NLP models
+/// stay resident across calls. Requests and responses are newline-delimited
+/// JSON correlated by an `id` field; a dead child is detected (broken pipe or
+/// EOF) and transparently respawned, surfacing `SidecarError::Restarted`.
+pub struct Sidecar {
+    daemon: Mutex<Option<DaemonHandle>>,
+    next_id: AtomicU64,
+    /// `(request id, child pid)` of the call currently being waited on, if
+    /// any. Kept outside of `daemon` so `cancel` can read the pid without
+    /// waiting on the in-flight call's lock.
+    inflight: Mutex<Option<(u64, u32)>>,
+    /// Request ids `cancel` has been asked to abort, whether or not they've
+    /// reached `inflight` yet. Also doubles as the flag the read loop checks
+    /// to report `Cancelled` instead of `Restarted`.
+    cancelled: Mutex<HashSet<u64>>,
+}
+
+impl Sidecar {
+    pub fn new() -> Self {
+        Self {
+            daemon: Mutex::new(None),
+            next_id: AtomicU64::new(1),
+            inflight: Mutex::new(None),
+            cancelled: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Reserve the request id that the next call will use, so a caller can
+    /// hand a correlatable run id to the frontend before the call completes.
+    pub fn next_run_id(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Mark `request_id` as cancelled, killing the daemon if it's already
+    /// running; a still-queued call picks this up in `call_with_id` before
+    /// dispatching.
+    pub fn cancel(&self, request_id: u64) -> bool {
+        self.cancelled.lock().unwrap().insert(request_id);
+        if let Some((id, pid)) = *self.inflight.lock().unwrap() {
+            if id == request_id {
+                kill_pid(pid);
+            }
+        }
+        true
+    }
+
+    fn spawn_daemon(scope: &ScopeConfig) -> Result<DaemonHandle, SidecarError> {
+        let mut cmd = sidecar_command(scope)?;
+        cmd.arg("--daemon")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = cmd
+            .spawn()
+            .map_err(|e| SidecarError::StartFailed(e.to_string()))?;
+
         let stdin = child
             .stdin
-            .as_mut()
+            .take()
             .ok_or_else(|| SidecarError::StartFailed("no stdin".into()))?;
-        let payload_bytes =
-            serde_json::to_vec(&payload).map_err(|e| SidecarError::InvalidOutput(e.to_string()))?;
-        stdin
-            .write_all(&payload_bytes)
-            .map_err(|e| SidecarError::StartFailed(e.to_string()))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| SidecarError::StartFailed("no stdout".into()))?;
+
+        Ok(DaemonHandle {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        })
+    }
+
+    /// Send one request to the daemon and wait for the matching response,
+    /// respawning the daemon transparently if it has died.
+    pub fn call(
+        &self,
+        scope: &ScopeConfig,
+        command: &str,
+        payload: Value,
+    ) -> Result<PythonResponse, SidecarError> {
+        self.call_streaming(scope, command, payload, |_| {})
     }
 
-    let output = child
-        .wait_with_output()
-        .map_err(|e| SidecarError::StartFailed(e.to_string()))?;
+    /// Like [`Sidecar::call`], but for commands that emit zero or more
+    /// `{"type":"progress",...}` lines before their final response line.
+    /// `on_event` is invoked for each progress line; any other (or missing)
+    /// `type` is treated as the final result.
+    pub fn call_streaming<F>(
+        &self,
+        scope: &ScopeConfig,
+        command: &str,
+        payload: Value,
+        on_event: F,
+    ) -> Result<PythonResponse, SidecarError>
+    where
+        F: FnMut(&Value),
+    {
+        self.call_with_id(scope, self.next_run_id(), command, payload, on_event)
+    }
+
+    /// Like [`Sidecar::call_streaming`], but lets the caller pin the request
+    /// id ahead of time (reserved via [`Sidecar::next_run_id`]) so it can be
+    /// handed to the frontend as a cancellable run id before this returns.
+    pub fn call_with_id<F>(
+        &self,
+        scope: &ScopeConfig,
+        id: u64,
+        command: &str,
+        payload: Value,
+        mut on_event: F,
+    ) -> Result<PythonResponse, SidecarError>
+    where
+        F: FnMut(&Value),
+    {
+        let mut guard = self.daemon.lock().unwrap();
+
+        // Honor a cancel that fired while this call was queued for `guard`.
+        if self.cancelled.lock().unwrap().remove(&id) {
+            return Err(SidecarError::Cancelled);
+        }
+
+        if guard.is_none() {
+            *guard = Some(Self::spawn_daemon(scope)?);
+        }
+
+        let request = serde_json::json!({ "id": id, "command": command, "payload": payload });
+        let mut line =
+            serde_json::to_vec(&request).map_err(|e| SidecarError::InvalidOutput(e.to_string()))?;
+        line.push(b'\n');
+
+        {
+            let handle = guard.as_mut().expect("daemon just spawned");
+            if handle.stdin.write_all(&line).and_then(|_| handle.stdin.flush()).is_err() {
+                *guard = Some(Self::spawn_daemon(scope)?);
+                return Err(SidecarError::Restarted);
+            }
+            *self.inflight.lock().unwrap() = Some((id, handle.child.id()));
+        }
+
+        let result = loop {
+            let mut response_line = String::new();
+            let read = {
+                let handle = guard.as_mut().expect("daemon just spawned");
+                handle.stdout.read_line(&mut response_line)
+            };
+
+            match read {
+                Ok(0) | Err(_) => {
+                    // EOF on stdout or a read error both mean the child died,
+                    // whether on its own or because `cancel` killed it.
+                    let _ = guard.take().map(|mut h| h.child.kill());
+                    *guard = Some(Self::spawn_daemon(scope)?);
+                    let was_cancelled = self.cancelled.lock().unwrap().remove(&id);
+                    break Err(if was_cancelled {
+                        SidecarError::Cancelled
+                    } else {
+                        SidecarError::Restarted
+                    });
+                }
+                Ok(_) => {}
+            }
+
+            let data: Value = match serde_json::from_str(response_line.trim())
+                .map_err(|e| SidecarError::InvalidOutput(format!("{e}. line={response_line}")))
+            {
+                Ok(data) => data,
+                Err(e) => break Err(e),
+            };
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-        return Err(SidecarError::NonZero(stderr));
+            let response_id = data.get("id").and_then(Value::as_u64);
+            if response_id != Some(id) {
+                // An out-of-order line leaves the stream unsynced for every
+                // later call, so respawn rather than just erroring this one.
+                let _ = guard.take().map(|mut h| h.child.kill());
+                *guard = Some(Self::spawn_daemon(scope)?);
+                break Err(SidecarError::InvalidOutput(format!(
+                    "response id mismatch: expected {id}, got {:?}",
+                    response_id
+                )));
+            }
+
+            if let Some(error) = data.get("error") {
+                break Err(SidecarError::NonZero(error.to_string()));
+            }
+
+            if data.get("type").and_then(Value::as_str) == Some("progress") {
+                on_event(&data);
+                continue;
+            }
+
+            break Ok(PythonResponse { data });
+        };
+
+        *self.inflight.lock().unwrap() = None;
+        self.cancelled.lock().unwrap().remove(&id);
+        result
+    }
+}
+
+impl Default for Sidecar {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+/// Maps the user-facing `run_id` strings handed to the frontend back to the
+/// internal request id a [`Sidecar`] call is keyed on.
+pub struct RunRegistry {
+    runs: Mutex<HashMap<String, u64>>,
+}
 
-    // Check for error in response
-    let data: Value = serde_json::from_str(&stdout)
-        .map_err(|e| SidecarError::InvalidOutput(format!("{e}. stdout={stdout}")))?;
+impl RunRegistry {
+    pub fn new() -> Self {
+        Self {
+            runs: Mutex::new(HashMap::new()),
+        }
+    }
 
-    if let Some(error) = data.get("error") {
-        return Err(SidecarError::NonZero(error.to_string()));
+    pub fn register(&self, run_id: String, request_id: u64) {
+        self.runs.lock().unwrap().insert(run_id, request_id);
     }
 
-    Ok(PythonResponse { data })
+    pub fn unregister(&self, run_id: &str) {
+        self.runs.lock().unwrap().remove(run_id);
+    }
+
+    pub fn request_id_for(&self, run_id: &str) -> Option<u64> {
+        self.runs.lock().unwrap().get(run_id).copied()
+    }
 }
 
-// Legacy function for backwards compatibility
-pub fn run_python_analyze_text(
-    req: crate::commands::Preset,
-    text: String,
-) -> Result<PythonResponse, SidecarError> {
-    let payload = serde_json::json!({
-        "text": text,
-        "preset": req
-    });
-    run_python_command("analyze_text", payload)
+impl Default for RunRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -0,0 +1,197 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use thiserror::Error;
+
+use crate::python_sidecar::SidecarError;
+
+#[derive(Debug, Error)]
+pub enum ScopeError {
+    #[error("failed to read scope config: {0}")]
+    ReadFailed(String),
+    #[error("failed to parse scope config: {0}")]
+    ParseFailed(String),
+}
+
+/// On-disk shape of `scope.config.json`. See the checked-in
+/// `scope.config.json` next to this crate's `Cargo.toml` for a working
+/// example; copy and edit it for a production deployment.
+#[derive(Debug, Deserialize)]
+struct RawScopeConfig {
+    /// Path (or bare name resolved via `PATH`) of the Python interpreter or
+    /// venv the sidecar is launched with. Used by debug builds, the app and
+    /// standalone tools alike; a release build launches the bundled
+    /// externalBin instead and ignores this field.
+    python_interpreter: PathBuf,
+    /// Directories `model_path` arguments must resolve inside of.
+    model_roots: Vec<PathBuf>,
+    /// Directories `input_path` arguments must resolve inside of.
+    input_roots: Vec<PathBuf>,
+    /// Directories the sidecar is allowed to report writing output into.
+    output_roots: Vec<PathBuf>,
+}
+
+/// Allowlist of filesystem locations the sidecar is permitted to touch,
+/// loaded once at startup from `scope.config.json` into Tauri managed state.
+/// Every `model_path`/`input_path`/`output_path` is canonicalized and
+/// checked against these roots before it reaches (or is trusted back from)
+/// the Python sidecar.
+#[derive(Debug, Clone)]
+pub struct ScopeConfig {
+    pub python_interpreter: PathBuf,
+    model_roots: Vec<PathBuf>,
+    input_roots: Vec<PathBuf>,
+    output_roots: Vec<PathBuf>,
+}
+
+impl ScopeConfig {
+    /// Load from `scope.config.json` next to the dev working directory, or
+    /// the app's resource directory in a release build.
+    pub fn load(app: &AppHandle) -> Result<Self, ScopeError> {
+        Self::load_from(&Self::config_path(app)?)
+    }
+
+    /// Load without a Tauri `AppHandle`, for standalone tools like
+    /// `bench_workload`. Always uses the dev-relative config path.
+    pub fn load_standalone() -> Result<Self, ScopeError> {
+        Self::load_from(&PathBuf::from("scope.config.json"))
+    }
+
+    fn load_from(config_path: &Path) -> Result<Self, ScopeError> {
+        let raw = std::fs::read_to_string(config_path)
+            .map_err(|e| ScopeError::ReadFailed(format!("{}: {e}", config_path.display())))?;
+        let raw: RawScopeConfig =
+            serde_json::from_str(&raw).map_err(|e| ScopeError::ParseFailed(e.to_string()))?;
+
+        Ok(Self {
+            python_interpreter: raw.python_interpreter,
+            model_roots: canonicalize_roots("model_roots", &raw.model_roots),
+            input_roots: canonicalize_roots("input_roots", &raw.input_roots),
+            output_roots: canonicalize_roots("output_roots", &raw.output_roots),
+        })
+    }
+
+    #[cfg(debug_assertions)]
+    fn config_path(_app: &AppHandle) -> Result<PathBuf, ScopeError> {
+        Ok(PathBuf::from("scope.config.json"))
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn config_path(app: &AppHandle) -> Result<PathBuf, ScopeError> {
+        let resource_dir = app
+            .path()
+            .resource_dir()
+            .map_err(|e| ScopeError::ReadFailed(e.to_string()))?;
+        Ok(resource_dir.join("scope.config.json"))
+    }
+
+    pub fn validate_input_path(&self, path: &str) -> Result<PathBuf, SidecarError> {
+        validate_within(path, &self.input_roots)
+    }
+
+    pub fn validate_model_path(&self, path: &str) -> Result<PathBuf, SidecarError> {
+        validate_within(path, &self.model_roots)
+    }
+
+    pub fn validate_output_path(&self, path: &str) -> Result<PathBuf, SidecarError> {
+        validate_within(path, &self.output_roots)
+    }
+}
+
+/// Canonicalize each configured root, warning about (and dropping) any that
+/// don't resolve, e.g. a typo'd path or a directory not yet created.
+fn canonicalize_roots(field: &str, roots: &[PathBuf]) -> Vec<PathBuf> {
+    roots
+        .iter()
+        .filter_map(|root| match std::fs::canonicalize(root) {
+            Ok(canonical) => Some(canonical),
+            Err(e) => {
+                eprintln!(
+                    "scope.config.json: {field} entry {} could not be resolved and will be ignored: {e}",
+                    root.display()
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+/// Canonicalize `path` and reject it unless it resolves under one of
+/// `roots`, closing off `..` traversal and symlink escapes alike.
+fn validate_within(path: &str, roots: &[PathBuf]) -> Result<PathBuf, SidecarError> {
+    let canonical = std::fs::canonicalize(path)
+        .map_err(|e| SidecarError::ScopeDenied(format!("cannot resolve {path}: {e}")))?;
+
+    if roots.iter().any(|root| canonical.starts_with(root)) {
+        Ok(canonical)
+    } else {
+        Err(SidecarError::ScopeDenied(format!(
+            "{} is outside the allowed roots",
+            canonical.display()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let n = COUNTER.fetch_add(1, AtomicOrdering::SeqCst);
+        let dir = std::env::temp_dir().join(format!("scope_test_{label}_{}_{n}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn validate_within_accepts_path_inside_root() {
+        let root = temp_dir("inside");
+        let file = root.join("doc.txt");
+        std::fs::write(&file, b"hello").unwrap();
+        let root = std::fs::canonicalize(&root).unwrap();
+
+        assert!(validate_within(file.to_str().unwrap(), &[root]).is_ok());
+    }
+
+    #[test]
+    fn validate_within_rejects_dot_dot_traversal() {
+        let root = temp_dir("traversal_root");
+        let sibling = temp_dir("traversal_sibling");
+        std::fs::write(sibling.join("secret.txt"), b"secret").unwrap();
+        let escaped = root
+            .join("..")
+            .join(sibling.file_name().unwrap())
+            .join("secret.txt");
+        let root = std::fs::canonicalize(&root).unwrap();
+
+        let result = validate_within(escaped.to_str().unwrap(), &[root]);
+        assert!(matches!(result, Err(SidecarError::ScopeDenied(_))));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn validate_within_rejects_symlink_escape() {
+        let root = temp_dir("symlink_root");
+        let outside = temp_dir("symlink_outside");
+        std::fs::write(outside.join("secret.txt"), b"secret").unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("escape")).unwrap();
+        let canonical_root = std::fs::canonicalize(&root).unwrap();
+
+        let result = validate_within(
+            root.join("escape").join("secret.txt").to_str().unwrap(),
+            &[canonical_root],
+        );
+        assert!(matches!(result, Err(SidecarError::ScopeDenied(_))));
+    }
+
+    #[test]
+    fn canonicalize_roots_drops_unresolvable_entries() {
+        let missing = std::env::temp_dir().join("scope_test_does_not_exist");
+        let _ = std::fs::remove_dir_all(&missing);
+
+        assert!(canonicalize_roots("test_roots", &[missing]).is_empty());
+    }
+}